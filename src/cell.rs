@@ -0,0 +1,144 @@
+use core::cell::UnsafeCell;
+use core::mem::MaybeUninit;
+
+use portable_atomic::{AtomicU8, Ordering};
+
+use crate::state::{EMPTY, FULL, INITIALIZING, MUT_FULL};
+
+#[derive(Debug)]
+pub struct StaticCell<T> {
+    state: AtomicU8,
+    value: UnsafeCell<MaybeUninit<T>>,
+}
+
+unsafe impl<T> Sync for StaticCell<T> where T: Sync {}
+
+impl<T> Default for StaticCell<T> where T: 'static {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> StaticCell<T> {
+    pub const fn new() -> Self
+    where
+        Self: 'static,
+    {
+        Self {
+            state: AtomicU8::new(EMPTY),
+            value: UnsafeCell::new(MaybeUninit::uninit()),
+        }
+    }
+
+    /// Tries to move `value` into the cell.
+    ///
+    /// This is safe: at most one caller ever wins the race to initialize the
+    /// cell. Losers (including callers arriving while another thread is in
+    /// the middle of initializing) get their `value` handed back in `Err`.
+    pub fn try_set(&'static self, value: T) -> Result<(), T> {
+        if self
+            .state
+            .compare_exchange(EMPTY, INITIALIZING, Ordering::Acquire, Ordering::Acquire)
+            .is_err()
+        {
+            return Err(value);
+        }
+        unsafe {
+            (*self.value.get()).write(value);
+        }
+        self.state.store(FULL, Ordering::Release);
+        Ok(())
+    }
+
+    /// Returns the value, if the cell has been fully initialized.
+    #[inline]
+    pub fn get(&'static self) -> Option<&'static T> {
+        if self.state.load(Ordering::Acquire) == FULL {
+            Some(unsafe { self.get_unchecked() })
+        } else {
+            None
+        }
+    }
+
+    /// Tries to move `value` into the cell and hand back an exclusive
+    /// reference to it instead of the shared access `try_set`/`get` give.
+    ///
+    /// Sound for the same reason `try_set` is: the atomic guard ensures this
+    /// runs at most once. The cell lands in a distinct `MUT_FULL` state
+    /// (rather than `FULL`), so `get` can never hand out a `&'static T` that
+    /// aliases the `&'static mut T` returned here, and a second `try_set_mut`
+    /// (or `try_set`) on the same cell still fails.
+    #[allow(clippy::mut_from_ref)] // `state` guarantees this runs at most once.
+    pub fn try_set_mut(&'static self, value: T) -> Result<&'static mut T, T> {
+        if self
+            .state
+            .compare_exchange(EMPTY, INITIALIZING, Ordering::Acquire, Ordering::Acquire)
+            .is_err()
+        {
+            return Err(value);
+        }
+        unsafe {
+            (*self.value.get()).write(value);
+            self.state.store(MUT_FULL, Ordering::Release);
+            Ok((*self.value.get()).assume_init_mut())
+        }
+    }
+
+    /// # Safety
+    /// The caller must guarantee that the cell has already been fully
+    /// initialized, e.g. by holding an [`crate::Inited`] token for it.
+    #[inline]
+    pub(crate) unsafe fn get_unchecked(&'static self) -> &'static T {
+        unsafe { (*self.value.get()).assume_init_ref() }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::boxed::Box;
+
+    #[test]
+    fn it_works() {
+        let t = Box::new(StaticCell::<usize>::new());
+        let t = Box::leak(t);
+
+        assert!(t.get().is_none());
+        t.try_set(42).unwrap();
+        assert_eq!(t.get(), Some(&42));
+    }
+
+    #[test]
+    fn double_init_hands_value_back() {
+        static CELL: StaticCell<&'static str> = StaticCell::new();
+        let cell = &CELL;
+
+        cell.try_set("first").unwrap();
+        assert_eq!(cell.try_set("second"), Err("second"));
+        assert_eq!(cell.get(), Some(&"first"));
+    }
+
+    #[test]
+    fn try_set_mut_hands_out_exclusive_ref() {
+        static CELL: StaticCell<usize> = StaticCell::new();
+        let cell = &CELL;
+
+        let value = cell.try_set_mut(1).unwrap();
+        *value += 1;
+        assert_eq!(*value, 2);
+
+        assert_eq!(cell.try_set_mut(3), Err(3));
+    }
+
+    #[test]
+    fn try_set_mut_cell_is_invisible_to_get() {
+        static CELL: StaticCell<u32> = StaticCell::new();
+        let cell = &CELL;
+
+        let value = cell.try_set_mut(1).unwrap();
+        assert!(cell.get().is_none());
+        assert_eq!(cell.try_set(2), Err(2));
+        *value += 1;
+        assert_eq!(*value, 2);
+    }
+}
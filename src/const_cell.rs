@@ -0,0 +1,75 @@
+use core::cell::UnsafeCell;
+
+use portable_atomic::{AtomicBool, Ordering};
+
+/// A `'static` value, known at compile time, that can be handed out as a
+/// single exclusive `&'static mut T` at runtime.
+///
+/// Useful for giving ownership of a statically-allocated buffer (or any
+/// value that doesn't need lazy construction) to exactly one consumer
+/// without heap allocation.
+#[derive(Debug)]
+pub struct ConstStaticCell<T> {
+    taken: AtomicBool,
+    value: UnsafeCell<T>,
+}
+
+unsafe impl<T> Sync for ConstStaticCell<T> where T: Send {}
+
+impl<T> ConstStaticCell<T> {
+    pub const fn new(value: T) -> Self {
+        Self {
+            taken: AtomicBool::new(false),
+            value: UnsafeCell::new(value),
+        }
+    }
+
+    /// Takes the exclusive reference to the value.
+    ///
+    /// # Panics
+    /// Panics if called more than once. See [`Self::try_take`] for a
+    /// non-panicking version.
+    pub fn take(&'static self) -> &'static mut T {
+        self.try_take().expect("ConstStaticCell already taken")
+    }
+
+    /// Takes the exclusive reference to the value, or `None` if it has
+    /// already been taken.
+    #[allow(clippy::mut_from_ref)] // `taken` guarantees this runs at most once.
+    pub fn try_take(&'static self) -> Option<&'static mut T> {
+        if self
+            .taken
+            .compare_exchange(false, true, Ordering::Acquire, Ordering::Acquire)
+            .is_ok()
+        {
+            Some(unsafe { &mut *self.value.get() })
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn take_once() {
+        static CELL: ConstStaticCell<[u8; 4]> = ConstStaticCell::new([0; 4]);
+
+        let buf = CELL.try_take().unwrap();
+        buf[0] = 1;
+        assert_eq!(buf, &[1, 0, 0, 0]);
+
+        assert!(CELL.try_take().is_none());
+    }
+
+    #[test]
+    #[should_panic(expected = "already taken")]
+    fn take_twice_panics() {
+        static CELL: ConstStaticCell<u8> = ConstStaticCell::new(0);
+
+        let _first = CELL.take();
+        let _second = CELL.take();
+    }
+}
@@ -0,0 +1,145 @@
+use core::cell::UnsafeCell;
+use core::marker::PhantomData;
+use core::mem::MaybeUninit;
+
+use portable_atomic::{AtomicU8, Ordering};
+
+use crate::relax::{RelaxStrategy, Spin};
+use crate::state::{EMPTY, FULL, INITIALIZING, POISONED};
+
+/// Transitions the cell's state to [`POISONED`] on drop. Only ever dropped
+/// while unwinding: the normal path `mem::forget`s it once the initializer
+/// returns without panicking.
+struct PoisonGuard<'a> {
+    state: &'a AtomicU8,
+}
+
+impl Drop for PoisonGuard<'_> {
+    fn drop(&mut self) {
+        self.state.store(POISONED, Ordering::Release);
+    }
+}
+
+/// A value that is lazily initialized on first access, modeled on
+/// [`core::cell::LazyCell`] but safe to share across threads.
+///
+/// Concurrent first accesses are serialized: exactly one caller runs `F`,
+/// the rest wait (per `R: `[`RelaxStrategy`]) for it to finish and then
+/// observe its result.
+#[derive(Debug)]
+pub struct StaticLazy<T, F = fn() -> T, R = Spin> {
+    state: AtomicU8,
+    init: UnsafeCell<Option<F>>,
+    value: UnsafeCell<MaybeUninit<T>>,
+    _relax: PhantomData<R>,
+}
+
+unsafe impl<T, F, R> Sync for StaticLazy<T, F, R>
+where
+    T: Sync,
+    F: Send,
+{
+}
+
+impl<T, F, R> StaticLazy<T, F, R> {
+    pub const fn new(f: F) -> Self {
+        Self {
+            state: AtomicU8::new(EMPTY),
+            init: UnsafeCell::new(Some(f)),
+            value: UnsafeCell::new(MaybeUninit::uninit()),
+            _relax: PhantomData,
+        }
+    }
+}
+
+impl<T, F, R> StaticLazy<T, F, R>
+where
+    F: FnOnce() -> T,
+    R: RelaxStrategy,
+{
+    /// Returns the value, running the initializer the first time this is
+    /// called. After that first call this is a plain acquire-load plus
+    /// pointer deref.
+    ///
+    /// # Panics
+    /// Panics if the initializer panicked on a previous call, leaving the
+    /// cell poisoned.
+    pub fn get(&'static self) -> &'static T {
+        loop {
+            match self.state.load(Ordering::Acquire) {
+                FULL => return unsafe { self.get_unchecked() },
+                POISONED => panic!("StaticLazy initializer panicked; cell is poisoned"),
+                EMPTY => {
+                    if self
+                        .state
+                        .compare_exchange(EMPTY, INITIALIZING, Ordering::Acquire, Ordering::Acquire)
+                        .is_ok()
+                    {
+                        let f = unsafe { &mut *self.init.get() }
+                            .take()
+                            .expect("StaticLazy initializer missing");
+                        let guard = PoisonGuard { state: &self.state };
+                        let value = f();
+                        core::mem::forget(guard);
+                        unsafe {
+                            (*self.value.get()).write(value);
+                        }
+                        self.state.store(FULL, Ordering::Release);
+                        return unsafe { self.get_unchecked() };
+                    }
+                }
+                _ => R::relax(),
+            }
+        }
+    }
+
+    #[inline]
+    unsafe fn get_unchecked(&'static self) -> &'static T {
+        unsafe { (*self.value.get()).assume_init_ref() }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::sync::atomic::{AtomicUsize, Ordering as StdOrdering};
+
+    #[test]
+    fn runs_initializer_once() {
+        static CALLS: AtomicUsize = AtomicUsize::new(0);
+        static LAZY: StaticLazy<usize> = StaticLazy::new(|| {
+            CALLS.fetch_add(1, StdOrdering::SeqCst);
+            42
+        });
+
+        assert_eq!(LAZY.get(), &42);
+        assert_eq!(LAZY.get(), &42);
+        assert_eq!(CALLS.load(StdOrdering::SeqCst), 1);
+    }
+
+    #[test]
+    fn concurrent_get_runs_initializer_once() {
+        static CALLS: AtomicUsize = AtomicUsize::new(0);
+        static LAZY: StaticLazy<usize> = StaticLazy::new(|| {
+            std::thread::yield_now();
+            CALLS.fetch_add(1, StdOrdering::SeqCst)
+        });
+
+        let handles: std::vec::Vec<_> = (0..16).map(|_| std::thread::spawn(|| *LAZY.get())).collect();
+        let results: std::vec::Vec<usize> = handles.into_iter().map(|h| h.join().unwrap()).collect();
+
+        assert_eq!(CALLS.load(StdOrdering::SeqCst), 1);
+        assert!(results.iter().all(|&value| value == results[0]));
+    }
+
+    #[test]
+    fn poisons_on_panic() {
+        static LAZY: StaticLazy<usize> = StaticLazy::new(|| panic!("boom"));
+
+        let first = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| LAZY.get()));
+        assert!(first.is_err());
+
+        let second = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| LAZY.get()));
+        assert!(second.is_err());
+    }
+}
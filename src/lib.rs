@@ -1,48 +1,37 @@
-use std::cell::UnsafeCell;
-use std::marker::PhantomData;
-use std::mem::MaybeUninit;
-
+#![no_std]
+
+#[cfg(any(feature = "std", test))]
+extern crate std;
+
+use core::fmt;
+use core::marker::PhantomData;
+
+mod cell;
+mod const_cell;
+mod lazy;
+mod relax;
+mod state;
+
+pub use cell::StaticCell;
+pub use const_cell::ConstStaticCell;
+pub use lazy::StaticLazy;
+pub use relax::{RelaxStrategy, Spin};
+#[cfg(feature = "std")]
+pub use relax::Yield;
+
+/// Error returned by [`StaticInit::init`] when the backing [`StaticCell`] was
+/// already initialized (or is concurrently being initialized) by someone
+/// else. Hands the value that couldn't be stored back to the caller.
 #[derive(Debug)]
-pub struct StaticCell<T> {
-    value: UnsafeCell<MaybeUninit<T>>,
-}
-
-unsafe impl<T> Sync for StaticCell<T> where T: Sync {}
+pub struct AlreadyInit<T>(pub T);
 
-impl<T> Default for StaticCell<T> where T: 'static {
-    fn default() -> Self {
-        Self::new()
+impl<T> fmt::Display for AlreadyInit<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "static cell is already initialized")
     }
 }
 
-impl<T> StaticCell<T> {
-    pub const fn new() -> Self
-    where
-        Self: 'static,
-    {
-        Self {
-            value: UnsafeCell::new(MaybeUninit::uninit()),
-        }
-    }
-
-    /// # Safety
-    /// This function is unsafe because it's up to the caller to ensure that
-    /// 1. the value is initialized.
-    /// 2. no other caller *write* it (call set method) at the same time.
-    #[inline]
-    pub unsafe fn get(&'static self) -> &'static T {
-        (*self.value.get()).assume_init_ref()
-    }
-
-    /// # Safety
-    /// This function is unsafe because it's up to the caller to ensure that
-    /// 1. no other caller *read* or *write* it (call get/set method) at the same time.
-    /// 2. set method should be called only once. (maybe call set multiple times is ok, but it's not recommended)
-    #[inline]
-    pub unsafe fn set(&'static self, value: T) {
-        (*self.value.get()).write(value);
-    }
-}
+impl<T: fmt::Debug> core::error::Error for AlreadyInit<T> {}
 
 pub trait StaticInit {
     type Item: 'static;
@@ -50,19 +39,21 @@ pub trait StaticInit {
     #[allow(clippy::declare_interior_mutable_const)]
     const HOLDER: &'static StaticCell<Self::Item>;
 
-    /// # Safety
-    /// can be called only once
+    /// Initializes the backing static cell.
     ///
     /// # Arguments
     ///
     /// * `value`: value for init
     ///
-    /// returns: Inited<Self> which can be used to get the reference of static value safely (and it is zero cost).
+    /// returns: `Ok(Inited<Self>)` which can be used to get the reference of
+    /// the static value safely (and it is zero cost), or `Err(AlreadyInit)`
+    /// if the cell was already initialized.
     ///
     /// # Examples
     ///
     /// ```
     ///  use static_once::{StaticCell, StaticInit};
+    ///  #[derive(Debug)]
     ///  struct A;
     ///
     ///  static __A__: StaticCell<A> = StaticCell::new();
@@ -72,19 +63,34 @@ pub trait StaticInit {
     ///     const HOLDER: &'static StaticCell<Self::Item> = &__A__;
     ///  }
     ///
-    ///  let inited = unsafe { A::init(A) };
+    ///  let inited = A::init(A).unwrap();
     ///
     ///  // here inited.get() is zero cost to get the reference of static value
     ///  // you can clone/copy the inited everywhere.
     ///  println!("{:p}", inited.get());
     /// ```
     #[allow(clippy::borrow_interior_mutable_const)]
-    unsafe fn init(value: Self::Item) -> Inited<Self>
+    fn init(value: Self::Item) -> Result<Inited<Self>, AlreadyInit<Self::Item>>
     where
         Self: Sized,
     {
-        Self::HOLDER.set(value);
-        Inited { _marker: PhantomData }
+        Self::HOLDER
+            .try_set(value)
+            .map(|()| Inited { _marker: PhantomData })
+            .map_err(AlreadyInit)
+    }
+
+    /// Initializes the backing static cell and hands back an exclusive
+    /// `&'static mut Self::Item` instead of an [`Inited`] token.
+    ///
+    /// `init` and `init_mut` are mutually exclusive on the same `HOLDER`:
+    /// pick one access story per type and stick to it.
+    #[allow(clippy::borrow_interior_mutable_const)]
+    fn init_mut(value: Self::Item) -> Result<&'static mut Self::Item, AlreadyInit<Self::Item>>
+    where
+        Self: Sized,
+    {
+        Self::HOLDER.try_set_mut(value).map_err(AlreadyInit)
     }
 }
 
@@ -101,7 +107,7 @@ impl<B> Inited<B> {
     where
         B: StaticInit,
     {
-        unsafe { B::HOLDER.get() }
+        unsafe { B::HOLDER.get_unchecked() }
     }
 }
 
@@ -122,15 +128,10 @@ mod tests {
 
     #[test]
     fn it_works() {
-        let t = Box::new(StaticCell::<usize>::new());
-        let t = Box::leak(t);
-
-        let a = unsafe { t.get() };
-
-        println!("{:p}", a);
+        let inited = A::init(A).unwrap();
+        std::println!("{:p}", inited.get());
+        std::println!("{:p}", A::HOLDER);
 
-        let inited = unsafe { A::init(A) };
-        println!("{:p}", inited.get());
-        println!("{:p}", A::HOLDER);
+        assert!(A::init(A).is_err());
     }
 }
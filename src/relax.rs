@@ -0,0 +1,34 @@
+//! Waiting strategies for [`crate::StaticLazy`], mirroring `spin::Once`'s
+//! `RelaxStrategy` so the crate stays usable on bare-metal targets where
+//! `std::sync::Once`/`std::thread::yield_now` don't exist.
+
+/// Customizes how a thread waits while another thread is running a
+/// [`crate::StaticLazy`] initializer.
+pub trait RelaxStrategy {
+    /// Called in a loop while waiting for the other thread to finish.
+    fn relax();
+}
+
+/// Spins using a CPU hint. Works on any target, including `no_std`/embedded.
+#[derive(Debug)]
+pub struct Spin;
+
+impl RelaxStrategy for Spin {
+    #[inline]
+    fn relax() {
+        core::hint::spin_loop();
+    }
+}
+
+/// Yields the current OS thread. Requires the `std` feature.
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub struct Yield;
+
+#[cfg(feature = "std")]
+impl RelaxStrategy for Yield {
+    #[inline]
+    fn relax() {
+        std::thread::yield_now();
+    }
+}
@@ -0,0 +1,17 @@
+//! Shared one-shot-initialization state machine used by [`crate::StaticCell`]
+//! and [`crate::StaticLazy`].
+
+/// Nothing has been stored yet.
+pub(crate) const EMPTY: u8 = 0;
+/// Some caller won the race and is currently writing the value.
+pub(crate) const INITIALIZING: u8 = 1;
+/// The value has been written via the shared-access path (`StaticCell::try_set`)
+/// and can be read through `get`.
+pub(crate) const FULL: u8 = 2;
+/// The value has been written via the exclusive-access path
+/// (`StaticCell::try_set_mut`) and was already handed out as `&'static mut T`;
+/// `get` must never return `Some` for this state, or it would alias that
+/// `&'static mut T`.
+pub(crate) const MUT_FULL: u8 = 3;
+/// The initializer panicked while running; the cell must not be read.
+pub(crate) const POISONED: u8 = 4;